@@ -1,13 +1,315 @@
-fn find_gcd(mut a: u32, mut b: u32) -> u32 {
-    while b != 0 {
-        let r = a % b;
+use std::io::{BufRead, BufReader};
+use std::ops::{Div, Mul};
+use std::path::{Path, PathBuf};
+
+/// Integer operations the Euclidean algorithm needs that plain `Rem`/`Neg`
+/// can't provide safely across both signed and unsigned types:
+///
+/// - `safe_rem` never panics, unlike `%`, which traps on `T::MIN % -1` for
+///   signed types (a hardware division-overflow trap, not a debug-only
+///   overflow check).
+/// - `abs_val` is a no-op for unsigned types and `Neg`-free for signed ones.
+///   Note: for a signed type's minimum value (e.g. `i64::MIN`), the true
+///   magnitude doesn't fit in `T`, so two's complement wraps it back to the
+///   same negative value rather than panicking. `find_gcd`/`find_lcm` can't
+///   return a non-negative result in that one case; this is a limitation of
+///   the type, not something this crate works around.
+trait GcdInt: Copy + PartialEq + Default {
+    fn safe_rem(self, other: Self) -> Self;
+    fn abs_val(self) -> Self;
+}
+
+macro_rules! impl_gcd_int_signed {
+    ($($t:ty),*) => {
+        $(impl GcdInt for $t {
+            fn safe_rem(self, other: Self) -> Self {
+                self.wrapping_rem(other)
+            }
+            fn abs_val(self) -> Self {
+                self.wrapping_abs()
+            }
+        })*
+    };
+}
+
+macro_rules! impl_gcd_int_unsigned {
+    ($($t:ty),*) => {
+        $(impl GcdInt for $t {
+            fn safe_rem(self, other: Self) -> Self {
+                self % other
+            }
+            fn abs_val(self) -> Self {
+                self
+            }
+        })*
+    };
+}
+
+impl_gcd_int_signed!(i8, i16, i32, i64, i128, isize);
+impl_gcd_int_unsigned!(u8, u16, u32, u64, u128, usize);
+
+fn find_gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: GcdInt,
+{
+    while b != T::default() {
+        let r = a.safe_rem(b);
         a = b;
         b = r;
     }
-    a
+    a.abs_val()
+}
+
+fn find_gcd_many<T>(nums: impl IntoIterator<Item = T>) -> Option<T>
+where
+    T: GcdInt,
+{
+    let mut nums = nums.into_iter().peekable();
+    nums.peek()?;
+    // Fold from zero (the gcd identity) rather than the raw first element so
+    // a single-element list is still normalized, e.g. `find_gcd_many([-5])`
+    // returns `5` instead of the unnormalized `-5`.
+    Some(nums.fold(T::default(), find_gcd))
+}
+
+fn find_lcm<T>(a: T, b: T) -> T
+where
+    T: GcdInt + Div<Output = T> + Mul<Output = T>,
+{
+    let gcd = find_gcd(a, b);
+    if gcd == T::default() {
+        return T::default();
+    }
+    a / gcd * b
+}
+
+fn find_lcm_many<T>(nums: impl IntoIterator<Item = T>) -> Option<T>
+where
+    T: GcdInt + Div<Output = T> + Mul<Output = T>,
+{
+    let mut nums = nums.into_iter();
+    let first = nums.next()?;
+    match nums.next() {
+        // A single-element list still needs normalizing: lcm(x, x) == abs(x).
+        None => Some(find_lcm(first, first)),
+        Some(second) => Some(nums.fold(find_lcm(first, second), find_lcm)),
+    }
+}
+
+fn read_nums_from_file(path: &str) -> Vec<i64> {
+    let contents = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("error: could not read '{path}': {e}");
+        std::process::exit(1);
+    });
+
+    let mut nums = Vec::new();
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse::<i64>() {
+            Ok(n) => nums.push(n),
+            Err(_) => {
+                eprintln!("error: '{path}' line {}: '{line}' is not a valid integer", i + 1);
+                std::process::exit(1);
+            }
+        }
+    }
+    nums
+}
+
+/// A flat `key=value` cache of previously computed GCDs, persisted to a
+/// dotfile so repeated runs over the same operands skip the Euclidean loop.
+struct Cache {
+    path: PathBuf,
+    lines: Vec<String>,
+}
+
+impl Cache {
+    /// Loads `.gcdcache` from `dir`, or starts empty if it doesn't exist yet.
+    fn read_or_generate(dir: &Path) -> Self {
+        let path = dir.join(".gcdcache");
+        let lines = match std::fs::File::open(&path) {
+            Ok(file) => BufReader::new(file).lines().map_while(Result::ok).collect(),
+            Err(_) => Vec::new(),
+        };
+        Cache { path, lines }
+    }
+
+    /// Returns the cached result for `key`, if any.
+    fn find(&self, key: &str) -> Option<i64> {
+        self.lines.iter().find_map(|line| {
+            let (k, v) = line.split_once('=')?;
+            (k == key).then(|| v.parse().ok()).flatten()
+        })
+    }
+
+    /// Records `key=value` in memory; call `write` to persist it.
+    fn append(&mut self, key: &str, value: i64) {
+        self.lines.push(format!("{key}={value}"));
+    }
+
+    /// Writes all cached entries back to the dotfile.
+    fn write(&self) -> std::io::Result<()> {
+        std::fs::write(&self.path, self.lines.join("\n") + "\n")
+    }
+}
+
+/// Builds a stable cache key from the operation and the normalized (sorted)
+/// operand list, so GCD and LCM results over the same operands don't collide.
+fn cache_key(op: &str, nums: &[i64]) -> String {
+    let mut sorted = nums.to_vec();
+    sorted.sort_unstable();
+    let operands = sorted
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{op}:{operands}")
 }
 
 fn main() {
-    let result = find_gcd(120, 48);
+    let mut nums = Vec::new();
+    let mut lcm = false;
+    let mut cache_dir = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--file" {
+            let path = args.next().unwrap_or_else(|| {
+                eprintln!("error: --file requires a path argument");
+                std::process::exit(1);
+            });
+            nums.extend(read_nums_from_file(&path));
+            continue;
+        }
+
+        if arg == "--lcm" {
+            lcm = true;
+            continue;
+        }
+
+        if arg == "--cache-dir" {
+            cache_dir = Some(args.next().unwrap_or_else(|| {
+                eprintln!("error: --cache-dir requires a path argument");
+                std::process::exit(1);
+            }));
+            continue;
+        }
+
+        match arg.parse::<i64>() {
+            Ok(n) => nums.push(n),
+            Err(_) => {
+                eprintln!("error: '{arg}' is not a valid integer");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if nums.is_empty() {
+        return;
+    }
+
+    // `--cache-dir` takes precedence, then `GCD_CACHE_DIR`, then the
+    // current directory, so the cache dotfile never lands somewhere
+    // unexpected by default.
+    let cache_dir = cache_dir
+        .or_else(|| std::env::var("GCD_CACHE_DIR").ok())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            std::env::current_dir().unwrap_or_else(|e| {
+                eprintln!("error: could not determine current directory: {e}");
+                std::process::exit(1);
+            })
+        });
+    let mut cache = Cache::read_or_generate(&cache_dir);
+    let op = if lcm { "lcm" } else { "gcd" };
+    let key = cache_key(op, &nums);
+
+    let result = match cache.find(&key) {
+        Some(hit) => hit,
+        None => {
+            let computed = if lcm {
+                find_lcm_many(nums).expect("nums is non-empty")
+            } else {
+                find_gcd_many(nums).expect("nums is non-empty")
+            };
+            cache.append(&key, computed);
+            if let Err(e) = cache.write() {
+                eprintln!("warning: could not write cache: {e}");
+            }
+            computed
+        }
+    };
+
     println!("{result}");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_basic() {
+        assert_eq!(find_gcd(120, 48), 24);
+    }
+
+    #[test]
+    fn gcd_with_zero() {
+        assert_eq!(find_gcd(0, 5), 5);
+        assert_eq!(find_gcd(5, 0), 5);
+        assert_eq!(find_gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn gcd_negative_normalizes_to_non_negative() {
+        assert_eq!(find_gcd(-12, 8), 4);
+        assert_eq!(find_gcd(-5, -10), 5);
+    }
+
+    #[test]
+    fn gcd_unsigned() {
+        assert_eq!(find_gcd::<u32>(12, 8), 4);
+    }
+
+    #[test]
+    fn gcd_min_by_neg_one_does_not_panic() {
+        assert_eq!(find_gcd(i64::MIN, -1), 1);
+    }
+
+    #[test]
+    fn gcd_many_single_element_normalizes() {
+        assert_eq!(find_gcd_many([-5]), Some(5));
+    }
+
+    #[test]
+    fn gcd_many_empty_is_none() {
+        assert_eq!(find_gcd_many(Vec::<i64>::new()), None);
+    }
+
+    #[test]
+    fn lcm_basic() {
+        assert_eq!(find_lcm(4, 6), 12);
+    }
+
+    #[test]
+    fn lcm_with_zero_is_zero() {
+        assert_eq!(find_lcm(0, 5), 0);
+        assert_eq!(find_lcm(0, 0), 0);
+    }
+
+    #[test]
+    fn lcm_negative_normalizes_to_non_negative() {
+        assert_eq!(find_lcm(-5, -10), 10);
+    }
+
+    #[test]
+    fn lcm_many_single_element_normalizes() {
+        assert_eq!(find_lcm_many([-5]), Some(5));
+    }
+
+    #[test]
+    fn lcm_many_empty_is_none() {
+        assert_eq!(find_lcm_many(Vec::<i64>::new()), None);
+    }
+}